@@ -0,0 +1,1076 @@
+use std::collections::BTreeMap;
+
+use azul_css::{LayoutRect, LayoutPoint, LayoutSize};
+use azul_core::{
+    id_tree::{NodeHierarchy, NodeDataContainer},
+    dom::NodeId,
+    ui_solver::PositionedRectangle,
+    traits::GetTextLayout,
+};
+
+use crate::{
+    RectContent,
+    number::Number,
+    geometry::{Size, Offsets},
+    style::{
+        Style, Display, Direction, FlexDirection, FlexWrap, Overflow,
+        AlignItems, AlignSelf, AlignContent, JustifyContent, PositionType,
+        Dimension, CalcNode, GridAutoFlow, TrackSizingFunction,
+    },
+};
+
+/// Lays out `root_id` and everything beneath it, writing one
+/// [`PositionedRectangle`] per node into the returned container (indexed the
+/// same way as `styles` / the original `NodeHierarchy`).
+pub(crate) fn compute<T: GetTextLayout>(
+    root_id: NodeId,
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+    bounds: LayoutSize,
+) -> NodeDataContainer<PositionedRectangle> {
+    let mut solved = NodeDataContainer::new(node_hierarchy.len());
+    let available = Size { width: Number::Defined(bounds.width), height: Number::Defined(bounds.height) };
+    layout(root_id, node_hierarchy, styles, rect_contents, available, Some(&mut solved));
+    solved
+}
+
+/// Measures the natural (content-based) size of `id` without writing any
+/// rectangles. Used by flex items / grid tracks whose sizing function needs
+/// to know how big their content wants to be.
+fn measure<T: GetTextLayout>(
+    id: NodeId,
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+    available: Size<Number>,
+) -> Size<f32> {
+    layout(id, node_hierarchy, styles, rect_contents, available, None)
+}
+
+fn children(node_hierarchy: &NodeHierarchy, id: NodeId) -> Vec<NodeId> {
+    let mut out = Vec::new();
+    let mut cur = node_hierarchy[id].first_child;
+    while let Some(child) = cur {
+        out.push(child);
+        cur = node_hierarchy[child].next_sibling;
+    }
+    out
+}
+
+/// Core recursive entry point. When `solved` is `Some`, the resolved
+/// rectangle of `id` (and everything beneath it) is written out; when it is
+/// `None` this is a throwaway measurement pass used to answer "how big does
+/// this content want to be" (see [`measure`]).
+fn layout<T: GetTextLayout>(
+    id: NodeId,
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+    available: Size<Number>,
+    mut solved: Option<&mut NodeDataContainer<PositionedRectangle>>,
+) -> Size<f32> {
+    let style = styles[id].clone();
+    let child_ids = children(node_hierarchy, id);
+
+    if style.display == Display::None {
+        if let Some(solved) = solved.as_deref_mut() {
+            write_rect(solved, id, 0.0, 0.0, 0.0, 0.0);
+        }
+        return Size { width: 0.0, height: 0.0 };
+    }
+
+    let natural_content = || -> Size<f32> {
+        content_size(id, &style, node_hierarchy, styles, rect_contents, available)
+    };
+
+    let width_from_css = resolve(style.size.width.clone(), available.width);
+    let height_from_css = resolve(style.size.height.clone(), available.height);
+
+    // `aspect-ratio` fills in whichever axis is still auto: from the other
+    // axis's definite size if one is set, or against the available space if
+    // both are auto. It never overrides a size the author specified on both
+    // axes.
+    let (width_from_css, height_from_css) = match (style.aspect_ratio, width_from_css, height_from_css) {
+        (Number::Defined(ratio), Number::Defined(w), Number::Undefined) =>
+            (Number::Defined(w), Number::Defined(w / ratio)),
+        (Number::Defined(ratio), Number::Undefined, Number::Defined(h)) =>
+            (Number::Defined(h * ratio), Number::Defined(h)),
+        (Number::Defined(ratio), Number::Undefined, Number::Undefined) => {
+            match (available.width, available.height) {
+                (Number::Defined(aw), _) => (Number::Defined(aw), Number::Defined(aw / ratio)),
+                (Number::Undefined, Number::Defined(ah)) => (Number::Defined(ah * ratio), Number::Defined(ah)),
+                (Number::Undefined, Number::Undefined) => (Number::Undefined, Number::Undefined),
+            }
+        },
+        (_, w, h) => (w, h),
+    };
+
+    let resolved_width = width_from_css.unwrap_or_else(|| if child_ids.is_empty() { natural_content().width } else { f32::NAN });
+    let resolved_height = height_from_css.unwrap_or_else(|| if child_ids.is_empty() { natural_content().height } else { f32::NAN });
+
+    let width = if resolved_width.is_nan() { None } else { Some(resolved_width) };
+    let height = if resolved_height.is_nan() { None } else { Some(resolved_height) };
+
+    let container_available = Size {
+        width: width.map(Number::Defined).unwrap_or(available.width),
+        height: height.map(Number::Defined).unwrap_or(available.height),
+    };
+
+    // Absolutely-positioned children are taken out of normal flow: they don't
+    // participate in flex/grid sizing or placement, and are positioned below
+    // against this box's own final size instead.
+    let (in_flow_ids, absolute_ids): (Vec<NodeId>, Vec<NodeId>) = child_ids.iter().copied()
+        .partition(|child_id| styles[*child_id].position_type != PositionType::Absolute);
+
+    let (content_w, content_h, positions) = match style.display {
+        Display::Grid => compute_grid(&in_flow_ids, node_hierarchy, styles, rect_contents, container_available),
+        Display::Flex | Display::Inline => compute_flex(&style, &in_flow_ids, node_hierarchy, styles, rect_contents, container_available),
+        Display::None => unreachable!(),
+    };
+
+    let final_width = width.unwrap_or(content_w);
+    let final_height = height.unwrap_or(content_h);
+
+    if let Some(solved) = solved.as_deref_mut() {
+        write_rect(solved, id, 0.0, 0.0, final_width, final_height);
+        for (child_id, (x, y, w, h)) in in_flow_ids.iter().zip(positions.into_iter()) {
+            let child_available = Size { width: Number::Defined(w), height: Number::Defined(h) };
+            layout(*child_id, node_hierarchy, styles, rect_contents, child_available, Some(solved));
+            offset_rect(solved, *child_id, x, y);
+        }
+        let containing_block = Size { width: Number::Defined(final_width), height: Number::Defined(final_height) };
+        for child_id in &absolute_ids {
+            let child_style = &styles[*child_id];
+            let size_hint = resolve_absolute_size(child_style, containing_block);
+            let child_size = layout(*child_id, node_hierarchy, styles, rect_contents, size_hint, Some(solved));
+            let (x, y) = resolve_absolute_offset(child_style, style.direction, containing_block, child_size);
+            offset_rect(solved, *child_id, x, y);
+        }
+    }
+
+    Size { width: final_width, height: final_height }
+}
+
+/// The size hint passed down to an absolutely-positioned child: definite
+/// where `left`+`right` (or `top`+`bottom`) pin both edges, the explicit
+/// `size` where set, or `Undefined` to let the child fall back to its own
+/// content size.
+fn resolve_absolute_size(style: &Style, containing_block: Size<Number>) -> Size<Number> {
+    let width = match resolve(style.size.width.clone(), containing_block.width) {
+        Number::Defined(w) => Number::Defined(w),
+        Number::Undefined => {
+            let left = resolve(style.position.left.clone(), containing_block.width);
+            let right = resolve(style.position.right.clone(), containing_block.width);
+            match (left, right, containing_block.width) {
+                (Number::Defined(l), Number::Defined(r), Number::Defined(cb)) => Number::Defined((cb - l - r).max(0.0)),
+                _ => Number::Undefined,
+            }
+        },
+    };
+    let height = match resolve(style.size.height.clone(), containing_block.height) {
+        Number::Defined(h) => Number::Defined(h),
+        Number::Undefined => {
+            let top = resolve(style.position.top.clone(), containing_block.height);
+            let bottom = resolve(style.position.bottom.clone(), containing_block.height);
+            match (top, bottom, containing_block.height) {
+                (Number::Defined(t), Number::Defined(b), Number::Defined(cb)) => Number::Defined((cb - t - b).max(0.0)),
+                _ => Number::Undefined,
+            }
+        },
+    };
+    Size { width, height }
+}
+
+/// Resolves an absolutely-positioned child's offset against its containing
+/// block and already-sized box: `left`/`top` win when set, `right`/`bottom`
+/// anchor from the far edge otherwise, and if neither edge is set the box
+/// falls back to the inline-start edge — the left edge in LTR, the right
+/// edge in RTL.
+fn resolve_absolute_offset(style: &Style, direction: Direction, containing_block: Size<Number>, size: Size<f32>) -> (f32, f32) {
+    let left = resolve(style.position.left.clone(), containing_block.width);
+    let right = resolve(style.position.right.clone(), containing_block.width);
+    let top = resolve(style.position.top.clone(), containing_block.height);
+    let bottom = resolve(style.position.bottom.clone(), containing_block.height);
+
+    let x = match (left, right) {
+        (Number::Defined(l), _) => l,
+        (Number::Undefined, Number::Defined(r)) => containing_block.width.unwrap_or(r + size.width) - r - size.width,
+        (Number::Undefined, Number::Undefined) =>
+            if direction == Direction::RTL { containing_block.width.unwrap_or(size.width) - size.width } else { 0.0 },
+    };
+    let y = match (top, bottom) {
+        (Number::Defined(t), _) => t,
+        (Number::Undefined, Number::Defined(b)) => containing_block.height.unwrap_or(b + size.height) - b - size.height,
+        (Number::Undefined, Number::Undefined) => 0.0,
+    };
+    (x, y)
+}
+
+fn write_rect(solved: &mut NodeDataContainer<PositionedRectangle>, id: NodeId, x: f32, y: f32, w: f32, h: f32) {
+    solved[id] = PositionedRectangle {
+        bounds: LayoutRect {
+            origin: LayoutPoint { x, y },
+            size: LayoutSize { width: w, height: h },
+        },
+    };
+}
+
+fn offset_rect(solved: &mut NodeDataContainer<PositionedRectangle>, id: NodeId, dx: f32, dy: f32) {
+    solved[id].bounds.origin.x += dx;
+    solved[id].bounds.origin.y += dy;
+}
+
+/// The content-based natural size of a node: for leaves this comes from the
+/// text/image content, for containers it is computed recursively.
+fn content_size<T: GetTextLayout>(
+    id: NodeId,
+    style: &Style,
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+    available: Size<Number>,
+) -> Size<f32> {
+    match rect_contents.get_mut(&id) {
+        Some(RectContent::Text(text)) => {
+            let laid_out = text.get_text_layout(available.width);
+            Size { width: laid_out.width, height: laid_out.height }
+        },
+        Some(RectContent::Image(w, h)) => {
+            let (w, h) = (*w as f32, *h as f32);
+            match style.aspect_ratio {
+                Number::Defined(ratio) => Size { width: w, height: w / ratio },
+                Number::Undefined => Size { width: w, height: h },
+            }
+        },
+        None => {
+            let child_ids = children(node_hierarchy, id);
+            if child_ids.is_empty() {
+                Size { width: 0.0, height: 0.0 }
+            } else {
+                measure(id, node_hierarchy, styles, rect_contents, available)
+            }
+        },
+    }
+}
+
+/// The *minimum* content-based size of a node, i.e. the automatic minimum
+/// main size a flex item gets when `min-width`/`min-height` is `auto`
+/// (CSS Flexbox §4.5). Unlike [`content_size`] this is never the item's
+/// preferred size: text is measured at zero available width (its longest
+/// unbreakable run) rather than at its full available width.
+fn min_content_size<T: GetTextLayout>(
+    id: NodeId,
+    style: &Style,
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+) -> Size<f32> {
+    match rect_contents.get_mut(&id) {
+        Some(RectContent::Text(text)) => {
+            let laid_out = text.get_text_layout(Number::Defined(0.0));
+            Size { width: laid_out.width, height: laid_out.height }
+        },
+        Some(RectContent::Image(w, h)) => {
+            let (w, h) = (*w as f32, *h as f32);
+            match style.aspect_ratio {
+                Number::Defined(ratio) => Size { width: w, height: w / ratio },
+                Number::Undefined => Size { width: w, height: h },
+            }
+        },
+        None => {
+            let child_ids: Vec<NodeId> = children(node_hierarchy, id).into_iter()
+                .filter(|child_id| styles[*child_id].position_type != PositionType::Absolute)
+                .collect();
+            let direction = style.flex_direction;
+            let mut main_sum = 0.0f32;
+            let mut cross_max = 0.0f32;
+            for child_id in &child_ids {
+                let child_style = &styles[*child_id];
+                let child_min = min_content_size(*child_id, child_style, node_hierarchy, styles, rect_contents);
+                main_sum += child_min.main(direction);
+                cross_max = cross_max.max(child_min.cross(direction));
+            }
+            if direction.is_row() { Size { width: main_sum, height: cross_max } } else { Size { width: cross_max, height: main_sum } }
+        },
+    }
+}
+
+/// Whether an item with `min-width`/`min-height: auto` gets the automatic
+/// content-based minimum size (CSS Flexbox §4.5), rather than a `0` floor:
+/// true for the initial `visible` overflow, false for anything that clips.
+fn uses_automatic_min_size(overflow: Overflow) -> bool {
+    overflow == Overflow::Visible
+}
+
+/// Resolves a style [`Dimension`] against a percentage basis.
+fn resolve(dimension: Dimension, basis: Number) -> Number {
+    match dimension {
+        Dimension::Pixels(px) => Number::Defined(px),
+        Dimension::Percent(pct) => match basis {
+            Number::Defined(basis) => Number::Defined(basis * pct / 100.0),
+            Number::Undefined => Number::Undefined,
+        },
+        Dimension::Calc(node) => eval_calc(&node, basis),
+        Dimension::Auto | Dimension::Undefined | Dimension::Content => Number::Undefined,
+    }
+}
+
+/// Whether a `calc()` subtree's result depends on the percentage basis.
+/// Used to reject `Div` when its divisor is percentage-dependent, since
+/// dividing by a percentage doesn't resolve to a fixed number either.
+fn calc_has_percent(node: &CalcNode) -> bool {
+    match node {
+        CalcNode::Px(_) => false,
+        CalcNode::Percent(_) => true,
+        CalcNode::Add(a, b) | CalcNode::Sub(a, b) | CalcNode::Mul(a, b) | CalcNode::Div(a, b) =>
+            calc_has_percent(a) || calc_has_percent(b),
+    }
+}
+
+/// Evaluates a parsed `calc()` tree against a resolved percentage basis, via
+/// a post-order walk: `Px` leaves pass through, `Percent` leaves resolve
+/// against `basis`, and `Add`/`Sub` combine two lengths. CSS disallows
+/// multiplying two lengths together, and `CalcNode` has no unitless-number
+/// leaf to act as the other operand, so `Mul` always resolves to
+/// `Number::Undefined`; `Div` only allows the non-percent-dependent divisor
+/// case (dividing by a percentage doesn't resolve to a fixed number either).
+fn eval_calc(node: &CalcNode, basis: Number) -> Number {
+    match node {
+        CalcNode::Px(px) => Number::Defined(*px),
+        CalcNode::Percent(pct) => match basis {
+            Number::Defined(basis) => Number::Defined(basis * pct / 100.0),
+            Number::Undefined => Number::Undefined,
+        },
+        CalcNode::Add(a, b) => match (eval_calc(a, basis), eval_calc(b, basis)) {
+            (Number::Defined(a), Number::Defined(b)) => Number::Defined(a + b),
+            _ => Number::Undefined,
+        },
+        CalcNode::Sub(a, b) => match (eval_calc(a, basis), eval_calc(b, basis)) {
+            (Number::Defined(a), Number::Defined(b)) => Number::Defined(a - b),
+            _ => Number::Undefined,
+        },
+        // `CalcNode` has no leaf for a plain unitless number, so both operands
+        // here are always lengths ("length * length" is invalid in CSS).
+        CalcNode::Mul(_, _) => Number::Undefined,
+        CalcNode::Div(a, b) => {
+            if calc_has_percent(b) {
+                return Number::Undefined;
+            }
+            match (eval_calc(a, basis), eval_calc(b, basis)) {
+                (Number::Defined(a), Number::Defined(b)) if b != 0.0 => Number::Defined(a / b),
+                _ => Number::Undefined,
+            }
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Flexbox
+// ---------------------------------------------------------------------------
+
+struct FlexItem {
+    id: NodeId,
+    basis: f32,
+    min_main: f32,
+    grow: f32,
+    shrink: f32,
+    margin: Offsets<f32>,
+    align_self: AlignSelf,
+}
+
+fn compute_flex<T: GetTextLayout>(
+    style: &Style,
+    child_ids: &[NodeId],
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+    available: Size<Number>,
+) -> (f32, f32, Vec<(f32, f32, f32, f32)>) {
+    let direction = style.flex_direction;
+    let main_available = available.main(direction);
+    let cross_available = available.cross(direction);
+
+    // RTL only flips the inline (row) axis: a `Row` container lays its items
+    // out right-to-left instead of left-to-right. `flex-start`/`flex-end` and
+    // `justify-content` are resolved as usual against an abstract start-to-end
+    // cursor below, then mirrored into physical coordinates here; columns are
+    // unaffected since `direction` only governs the inline axis.
+    let rtl_row = style.direction == Direction::RTL && direction.is_row();
+
+    // `column-gap` separates items along a row main axis (and flex lines
+    // along a column cross axis); `row-gap` is the mirror image.
+    let column_gap = resolve(style.column_gap.clone(), available.width).unwrap_or_zero();
+    let row_gap = resolve(style.row_gap.clone(), available.height).unwrap_or_zero();
+    let main_gap = if direction.is_row() { column_gap } else { row_gap };
+    let cross_gap = if direction.is_row() { row_gap } else { column_gap };
+
+    let items: Vec<FlexItem> = child_ids.iter().map(|child_id| {
+        let child_style = &styles[*child_id];
+        let margin = Offsets {
+            left: resolve(child_style.margin.left.clone(), available.width).unwrap_or_zero(),
+            right: resolve(child_style.margin.right.clone(), available.width).unwrap_or_zero(),
+            top: resolve(child_style.margin.top.clone(), available.height).unwrap_or_zero(),
+            bottom: resolve(child_style.margin.bottom.clone(), available.height).unwrap_or_zero(),
+        };
+        // `flex-basis` is the item's initial main size: `content` always
+        // measures the content, `auto` falls back to the main-axis `size`
+        // property (and only then to content), anything else resolves as a
+        // normal dimension (falling back to content if it's percentage-based
+        // against an indefinite container).
+        let basis = match child_style.flex_basis.clone() {
+            Dimension::Content => content_size(*child_id, child_style, node_hierarchy, styles, rect_contents, available).main(direction),
+            Dimension::Auto => resolve(child_style.size.main(direction).clone(), available.main(direction))
+                .unwrap_or_else(|| content_size(*child_id, child_style, node_hierarchy, styles, rect_contents, available).main(direction)),
+            explicit_basis => resolve(explicit_basis, available.main(direction))
+                .unwrap_or_else(|| content_size(*child_id, child_style, node_hierarchy, styles, rect_contents, available).main(direction)),
+        };
+        // A `min-width`/`min-height: auto` (the default, for a visible-overflow
+        // item) clamps to the content-based minimum rather than zero.
+        let min_main = match child_style.min_size.main(direction).clone() {
+            Dimension::Auto if uses_automatic_min_size(child_style.overflow) =>
+                min_content_size(*child_id, child_style, node_hierarchy, styles, rect_contents).main(direction),
+            min_dimension => resolve(min_dimension, available.main(direction)).unwrap_or_zero(),
+        };
+        FlexItem {
+            id: *child_id,
+            basis,
+            min_main,
+            grow: child_style.flex_grow,
+            shrink: child_style.flex_shrink,
+            margin,
+            align_self: child_style.align_self,
+        }
+    }).collect();
+
+    // Break items into flex lines: a single line when wrapping is off (or the
+    // container has no definite main size to wrap against).
+    let lines: Vec<Vec<usize>> = if style.flex_wrap == FlexWrap::NoWrap {
+        vec![(0..items.len()).collect()]
+    } else {
+        match main_available {
+            Number::Undefined => vec![(0..items.len()).collect()],
+            Number::Defined(limit) => {
+                let mut lines = Vec::new();
+                let mut current = Vec::new();
+                let mut current_main = 0.0;
+                for (i, item) in items.iter().enumerate() {
+                    let own_main = item.basis + item.margin.main(direction);
+                    if !current.is_empty() && current_main + main_gap + own_main > limit {
+                        lines.push(std::mem::take(&mut current));
+                        current_main = 0.0;
+                    }
+                    current_main += if current.is_empty() { own_main } else { main_gap + own_main };
+                    current.push(i);
+                }
+                if !current.is_empty() { lines.push(current); }
+                lines
+            },
+        }
+    };
+
+    let mut main_sizes = vec![0.0f32; items.len()];
+    let mut cross_sizes = vec![0.0f32; items.len()];
+    let mut line_cross_sizes = Vec::with_capacity(lines.len());
+
+    for line in &lines {
+        let line_gap_total = main_gap * line.len().saturating_sub(1) as f32;
+        let line_basis: f32 = line.iter().map(|&i| items[i].basis + items[i].margin.main(direction)).sum::<f32>() + line_gap_total;
+        let free_space = main_available.unwrap_or(line_basis) - line_basis;
+
+        if free_space > 0.0 {
+            let total_grow: f32 = line.iter().map(|&i| items[i].grow).sum();
+            if total_grow > 0.0 {
+                for &i in line {
+                    main_sizes[i] = items[i].basis + free_space * (items[i].grow / total_grow);
+                }
+            } else {
+                for &i in line { main_sizes[i] = items[i].basis; }
+            }
+        } else if free_space < 0.0 {
+            let total_shrink: f32 = line.iter().map(|&i| items[i].shrink * items[i].basis).sum();
+            if total_shrink > 0.0 {
+                for &i in line {
+                    let weight = items[i].shrink * items[i].basis;
+                    main_sizes[i] = (items[i].basis + free_space * (weight / total_shrink)).max(0.0);
+                }
+            } else {
+                for &i in line { main_sizes[i] = items[i].basis; }
+            }
+        } else {
+            for &i in line { main_sizes[i] = items[i].basis; }
+        }
+
+        // The resolved minimum main size always wins, even over a flex-shrink
+        // result that would otherwise squeeze the item smaller.
+        for &i in line { main_sizes[i] = main_sizes[i].max(items[i].min_main); }
+
+        let mut line_cross: f32 = 0.0;
+        for &i in line {
+            let child_style = &styles[items[i].id];
+            let cross_size = resolve(child_style.size.cross(direction).clone(), cross_available)
+                .unwrap_or_else(|| content_size(items[i].id, child_style, node_hierarchy, styles, rect_contents, available).cross(direction));
+            cross_sizes[i] = cross_size;
+            line_cross = line_cross.max(cross_size + items[i].margin.cross(direction));
+        }
+        line_cross_sizes.push(line_cross);
+    }
+
+    let mut positions = vec![(0.0, 0.0, 0.0, 0.0); items.len()];
+    let content_main = lines.iter().map(|line| {
+        line.iter().map(|&i| main_sizes[i] + items[i].margin.main(direction)).sum::<f32>()
+            + main_gap * line.len().saturating_sub(1) as f32
+    }).fold(0.0f32, f32::max);
+    let content_cross: f32 = line_cross_sizes.iter().sum::<f32>() + cross_gap * line_cross_sizes.len().saturating_sub(1) as f32;
+    let free_cross = (cross_available.unwrap_or(content_cross) - content_cross).max(0.0);
+    let (cross_start, align_gap, stretch_per_line) = align_content(style.align_content, free_cross, lines.len());
+    let line_cross_sizes: Vec<f32> = line_cross_sizes.iter().map(|&c| c + stretch_per_line).collect();
+
+    let mut cross_cursor = cross_start;
+    for (line, &line_cross) in lines.iter().zip(line_cross_sizes.iter()) {
+        let line_main: f32 = line.iter().map(|&i| main_sizes[i] + items[i].margin.main(direction)).sum::<f32>()
+            + main_gap * line.len().saturating_sub(1) as f32;
+        let line_box_main = main_available.unwrap_or(line_main);
+        let free_main = (line_box_main - line_main).max(0.0);
+        let any_grow = line.iter().any(|&i| items[i].grow > 0.0);
+        let (mut main_cursor, justify_gap) = if any_grow {
+            (0.0, 0.0)
+        } else {
+            justify(style.justify_content, free_main, line.len())
+        };
+
+        for &i in line {
+            let align = match items[i].align_self {
+                AlignSelf::Auto => style.align_items,
+                AlignSelf::FlexStart => AlignItems::FlexStart,
+                AlignSelf::FlexEnd => AlignItems::FlexEnd,
+                AlignSelf::Center => AlignItems::Center,
+                AlignSelf::Baseline => AlignItems::Baseline,
+                AlignSelf::Stretch => AlignItems::Stretch,
+            };
+            let cross_size = if align == AlignItems::Stretch
+                && resolve(styles[items[i].id].size.cross(direction).clone(), cross_available) == Number::Undefined
+            {
+                line_cross - items[i].margin.cross(direction)
+            } else {
+                cross_sizes[i]
+            };
+            let cross_offset = match align {
+                AlignItems::FlexStart | AlignItems::Baseline => 0.0,
+                AlignItems::FlexEnd => line_cross - cross_size - items[i].margin.cross(direction),
+                AlignItems::Center => (line_cross - cross_size - items[i].margin.cross(direction)) / 2.0,
+                AlignItems::Stretch => 0.0,
+            };
+
+            let main_pos = if rtl_row {
+                line_box_main - main_cursor - main_sizes[i] - items[i].margin.main_end(direction)
+            } else {
+                main_cursor + items[i].margin.main_start(direction)
+            };
+            let cross_pos = cross_cursor + cross_offset + items[i].margin.cross_start(direction);
+
+            positions[i] = if direction.is_row() {
+                (main_pos, cross_pos, main_sizes[i], cross_size)
+            } else {
+                (cross_pos, main_pos, cross_size, main_sizes[i])
+            };
+
+            main_cursor += main_sizes[i] + items[i].margin.main(direction) + justify_gap + main_gap;
+        }
+
+        cross_cursor += line_cross + cross_gap + align_gap;
+    }
+
+    let (content_w, content_h) = if direction.is_row() { (content_main, content_cross) } else { (content_cross, content_main) };
+
+    (content_w, content_h, positions)
+}
+
+/// Resolves `justify-content` into a (starting main-axis cursor, gap between
+/// items) pair given the leftover free space on a line.
+fn justify(justify_content: JustifyContent, free_space: f32, item_count: usize) -> (f32, f32) {
+    if item_count == 0 { return (0.0, 0.0); }
+    match justify_content {
+        JustifyContent::FlexStart => (0.0, 0.0),
+        JustifyContent::FlexEnd => (free_space, 0.0),
+        JustifyContent::Center => (free_space / 2.0, 0.0),
+        JustifyContent::SpaceBetween => {
+            if item_count == 1 { (0.0, 0.0) } else { (0.0, free_space / (item_count - 1) as f32) }
+        },
+        JustifyContent::SpaceAround => {
+            let gap = free_space / item_count as f32;
+            (gap / 2.0, gap)
+        },
+        JustifyContent::SpaceEvenly => {
+            let gap = free_space / (item_count + 1) as f32;
+            (gap, gap)
+        },
+    }
+}
+
+/// Resolves `align-content` into a (starting cross-axis cursor, gap between
+/// lines, extra cross size handed to every line) triple given the leftover
+/// cross-axis free space across all flex lines.
+fn align_content(align_content: AlignContent, free_space: f32, line_count: usize) -> (f32, f32, f32) {
+    if line_count == 0 { return (0.0, 0.0, 0.0); }
+    match align_content {
+        AlignContent::FlexStart => (0.0, 0.0, 0.0),
+        AlignContent::FlexEnd => (free_space, 0.0, 0.0),
+        AlignContent::Center => (free_space / 2.0, 0.0, 0.0),
+        AlignContent::Stretch => (0.0, 0.0, free_space / line_count as f32),
+        AlignContent::SpaceBetween => {
+            if line_count == 1 { (0.0, 0.0, 0.0) } else { (0.0, free_space / (line_count - 1) as f32, 0.0) }
+        },
+        AlignContent::SpaceAround => {
+            let gap = free_space / line_count as f32;
+            (gap / 2.0, gap, 0.0)
+        },
+    }
+}
+
+// ---------------------------------------------------------------------------
+// CSS Grid
+// ---------------------------------------------------------------------------
+
+struct Track {
+    sizing: TrackSizingFunction,
+    base_size: f32,
+    growth_limit: f32,
+}
+
+impl Track {
+    fn new(sizing: TrackSizingFunction) -> Track {
+        let (base_size, growth_limit) = match sizing {
+            TrackSizingFunction::Pixels(px) => (px, px),
+            TrackSizingFunction::Percent(_) => (0.0, 0.0), // resolved by caller against the container size
+            TrackSizingFunction::Fraction(_) => (0.0, f32::INFINITY),
+            TrackSizingFunction::Auto | TrackSizingFunction::MinContent | TrackSizingFunction::MaxContent => (0.0, f32::INFINITY),
+        };
+        Track { sizing, base_size, growth_limit }
+    }
+}
+
+struct GridItem {
+    id: NodeId,
+    column: (usize, usize), // [start, end) track index range
+    row: (usize, usize),
+}
+
+fn compute_grid<T: GetTextLayout>(
+    child_ids: &[NodeId],
+    node_hierarchy: &NodeHierarchy,
+    styles: &NodeDataContainer<Style>,
+    rect_contents: &mut BTreeMap<NodeId, RectContent<T>>,
+    available: Size<Number>,
+) -> (f32, f32, Vec<(f32, f32, f32, f32)>) {
+    if child_ids.is_empty() {
+        return (0.0, 0.0, Vec::new());
+    }
+
+    // Grid style lives on the container; every child shares it for the track
+    // definitions and auto-flow, but `grid-row`/`grid-column` placement is
+    // per-item.
+    let container_style = &styles[*node_hierarchy_parent_of(node_hierarchy, child_ids[0])
+        .unwrap_or(child_ids[0])];
+
+    let mut column_defs = container_style.grid_template_columns.clone();
+    let mut row_defs = container_style.grid_template_rows.clone();
+    if column_defs.is_empty() { column_defs.push(TrackSizingFunction::Auto); }
+    if row_defs.is_empty() { row_defs.push(TrackSizingFunction::Auto); }
+
+    let items = place_items(child_ids, styles, container_style.grid_auto_flow, column_defs.len(), row_defs.len());
+
+    // Grow the explicit grids to fit any item that was auto-placed past the end.
+    let columns_needed = items.iter().map(|i| i.column.1).max().unwrap_or(column_defs.len()).max(column_defs.len());
+    let rows_needed = items.iter().map(|i| i.row.1).max().unwrap_or(row_defs.len()).max(row_defs.len());
+    while column_defs.len() < columns_needed { column_defs.push(TrackSizingFunction::Auto); }
+    while row_defs.len() < rows_needed { row_defs.push(TrackSizingFunction::Auto); }
+
+    let column_gap = resolve(container_style.column_gap.clone(), available.width).unwrap_or_zero();
+    let row_gap = resolve(container_style.row_gap.clone(), available.height).unwrap_or_zero();
+
+    let column_sizes = size_tracks(&column_defs, available.width, column_gap, &items, &items.iter().map(|i| i.column).collect::<Vec<_>>(),
+        |item_id| measure(item_id, node_hierarchy, styles, rect_contents, Size::undefined()).width);
+    let row_sizes = size_tracks(&row_defs, available.height, row_gap, &items, &items.iter().map(|i| i.row).collect::<Vec<_>>(),
+        |item_id| measure(item_id, node_hierarchy, styles, rect_contents, Size::undefined()).height);
+
+    let column_offsets = prefix_sums(&column_sizes, column_gap);
+    let row_offsets = prefix_sums(&row_sizes, row_gap);
+
+    let mut positions = Vec::with_capacity(child_ids.len());
+    for child_id in child_ids {
+        let item = items.iter().find(|i| &i.id == child_id).expect("every child is placed");
+        let x = column_offsets[item.column.0];
+        let y = row_offsets[item.row.0];
+        let w: f32 = column_sizes[item.column.0..item.column.1].iter().sum::<f32>()
+            + column_gap * (item.column.1 - item.column.0).saturating_sub(1) as f32;
+        let h: f32 = row_sizes[item.row.0..item.row.1].iter().sum::<f32>()
+            + row_gap * (item.row.1 - item.row.0).saturating_sub(1) as f32;
+        positions.push((x, y, w, h));
+    }
+
+    let content_w = *column_offsets.last().unwrap();
+    let content_h = *row_offsets.last().unwrap();
+    (content_w, content_h, positions)
+}
+
+fn node_hierarchy_parent_of(node_hierarchy: &NodeHierarchy, id: NodeId) -> Option<NodeId> {
+    node_hierarchy[id].parent
+}
+
+fn prefix_sums(sizes: &[f32], gap: f32) -> Vec<f32> {
+    let mut out = Vec::with_capacity(sizes.len() + 1);
+    let mut total = 0.0;
+    out.push(0.0);
+    for (i, s) in sizes.iter().enumerate() {
+        if i > 0 { total += gap; }
+        total += s;
+        out.push(total);
+    }
+    out
+}
+
+/// Hard cap on how far an explicit `grid-row`/`grid-column` line number can
+/// push the grid. Without this, a single item with e.g. `grid-column: 1000000`
+/// would force `compute_grid` to allocate a million-plus tracks on that axis.
+const MAX_EXPLICIT_GRID_LINE: usize = 1_000;
+
+/// Converts an explicit `grid-row`/`grid-column`'s 1-based start line and
+/// span into a clamped `[start, end)` track index range, so a bogus huge
+/// line number can't push the grid past [`MAX_EXPLICIT_GRID_LINE`]` + 1`
+/// tracks.
+fn clamp_explicit_placement(start_line: i32, span: u32) -> (usize, usize) {
+    let start = ((start_line.max(1) - 1) as usize).min(MAX_EXPLICIT_GRID_LINE);
+    let end = (start + span.max(1) as usize).min(MAX_EXPLICIT_GRID_LINE + 1);
+    (start, end)
+}
+
+/// Auto-places every child into the explicit grid (growing the implicit grid
+/// along `auto_flow` for anything without an explicit `grid-row`/`grid-column`).
+fn place_items(
+    child_ids: &[NodeId],
+    styles: &NodeDataContainer<Style>,
+    auto_flow: GridAutoFlow,
+    explicit_columns: usize,
+    explicit_rows: usize,
+) -> Vec<GridItem> {
+    let mut items = Vec::with_capacity(child_ids.len());
+    // Cursor position the *next* fully-auto item will land on, in grid
+    // coordinates. Read before `advance_cursor` runs, not reconstructed from
+    // it afterwards, so it always reflects where the current item actually
+    // goes rather than undoing a wrap that belonged to a previous item.
+    let mut cursor_col = 0usize;
+    let mut cursor_row = 0usize;
+
+    for child_id in child_ids {
+        let style = &styles[*child_id];
+        let column_auto = style.grid_column.is_auto();
+        let row_auto = style.grid_row.is_auto();
+
+        let (column, row) = if column_auto && row_auto {
+            let placement = ((cursor_col, cursor_col + 1), (cursor_row, cursor_row + 1));
+            advance_cursor(auto_flow, explicit_columns, explicit_rows, &mut cursor_col, &mut cursor_row);
+            placement
+        } else {
+            let column = if column_auto {
+                (cursor_col, cursor_col + 1)
+            } else {
+                clamp_explicit_placement(style.grid_column.start_line, style.grid_column.span)
+            };
+            let row = if row_auto {
+                (cursor_row, cursor_row + 1)
+            } else {
+                clamp_explicit_placement(style.grid_row.start_line, style.grid_row.span)
+            };
+            (column, row)
+        };
+
+        items.push(GridItem { id: *child_id, column, row });
+    }
+
+    items
+}
+
+/// Advances the auto-placement cursor to the next cell along the flow axis
+/// (columns for `Row`, rows for `Column`), wrapping into the next cross-axis
+/// track once the explicit grid in the flow axis is exhausted.
+fn advance_cursor(
+    auto_flow: GridAutoFlow,
+    explicit_columns: usize,
+    explicit_rows: usize,
+    cursor_col: &mut usize,
+    cursor_row: &mut usize,
+) {
+    match auto_flow {
+        GridAutoFlow::Row => {
+            *cursor_col += 1;
+            if *cursor_col >= explicit_columns.max(1) {
+                *cursor_col = 0;
+                *cursor_row += 1;
+            }
+        },
+        GridAutoFlow::Column => {
+            *cursor_row += 1;
+            if *cursor_row >= explicit_rows.max(1) {
+                *cursor_row = 0;
+                *cursor_col += 1;
+            }
+        },
+    }
+}
+
+/// The track-sizing algorithm, run independently per axis:
+/// initialize -> resolve intrinsic sizes from spanning items -> maximize ->
+/// expand flexible (`fr`) tracks.
+fn size_tracks(
+    defs: &[TrackSizingFunction],
+    available: Number,
+    gap: f32,
+    items: &[GridItem],
+    spans: &[(usize, usize)],
+    content_contribution: impl Fn(NodeId) -> f32,
+) -> Vec<f32> {
+    let mut tracks: Vec<Track> = defs.iter().map(|d| Track::new(*d)).collect();
+    let gap_total = gap * (defs.len().saturating_sub(1)) as f32;
+
+    // Percentages resolve against the definite container size, if any.
+    if let Number::Defined(basis) = available {
+        for (track, def) in tracks.iter_mut().zip(defs.iter()) {
+            if let TrackSizingFunction::Percent(pct) = def {
+                track.base_size = basis * pct / 100.0;
+                track.growth_limit = track.base_size;
+            }
+        }
+    }
+
+    // 1 & 2: grow base sizes / growth limits from the content contribution of
+    // items that span exactly one track.
+    for (item, &(start, end)) in items.iter().zip(spans.iter()) {
+        if end - start != 1 { continue; }
+        let def = tracks[start].sizing;
+        if !def.is_intrinsic() { continue; }
+        let contribution = content_contribution(item.id);
+        tracks[start].base_size = tracks[start].base_size.max(contribution);
+        if tracks[start].growth_limit.is_finite() {
+            tracks[start].growth_limit = tracks[start].growth_limit.max(contribution);
+        } else {
+            tracks[start].growth_limit = contribution;
+        }
+    }
+
+    // 2b: items that span more than one track distribute their content
+    // contribution evenly across the intrinsic tracks in their span, after
+    // subtracting the space the span already has from its other tracks
+    // (a simplified stand-in for the spec's iterative multi-span distribution).
+    for (item, &(start, end)) in items.iter().zip(spans.iter()) {
+        if end - start <= 1 { continue; }
+        let span_tracks: Vec<usize> = (start..end.min(tracks.len())).collect();
+        let intrinsic_tracks: Vec<usize> = span_tracks.iter().copied().filter(|&i| tracks[i].sizing.is_intrinsic()).collect();
+        if intrinsic_tracks.is_empty() { continue; }
+        let existing: f32 = span_tracks.iter().map(|&i| tracks[i].base_size).sum::<f32>()
+            + gap * span_tracks.len().saturating_sub(1) as f32;
+        let contribution = content_contribution(item.id);
+        let extra = (contribution - existing).max(0.0);
+        if extra <= 0.0 { continue; }
+        let share = extra / intrinsic_tracks.len() as f32;
+        for &i in &intrinsic_tracks {
+            tracks[i].base_size += share;
+            if tracks[i].growth_limit.is_finite() {
+                tracks[i].growth_limit = tracks[i].growth_limit.max(tracks[i].base_size);
+            } else {
+                tracks[i].growth_limit = tracks[i].base_size;
+            }
+        }
+    }
+
+    // 3: maximize non-flexible tracks with the leftover container space, up
+    // to each track's growth limit (`fr` tracks are sized in step 4).
+    if let Number::Defined(available) = available {
+        let used: f32 = tracks.iter().map(|t| t.base_size).sum();
+        let mut leftover = (available - gap_total - used).max(0.0);
+        if leftover > 0.0 {
+            let growable: Vec<usize> = (0..tracks.len())
+                .filter(|&i| !tracks[i].sizing.is_flexible() && tracks[i].growth_limit > tracks[i].base_size)
+                .collect();
+            if !growable.is_empty() {
+                let share = leftover / growable.len() as f32;
+                for i in growable {
+                    let room = tracks[i].growth_limit - tracks[i].base_size;
+                    let grown = share.min(room.max(0.0));
+                    tracks[i].base_size += grown;
+                    leftover -= grown;
+                }
+            }
+        }
+    }
+
+    // 4: expand flexible `fr` tracks using the flex fraction = free space / sum(fr).
+    if let Number::Defined(available) = available {
+        let non_flex_used: f32 = tracks.iter().filter(|t| !t.sizing.is_flexible()).map(|t| t.base_size).sum();
+        let free_space = (available - gap_total - non_flex_used).max(0.0);
+        let total_fr: f32 = tracks.iter().filter_map(|t| match t.sizing {
+            TrackSizingFunction::Fraction(fr) => Some(fr),
+            _ => None,
+        }).sum();
+        if total_fr > 0.0 {
+            let fraction = free_space / total_fr;
+            for track in tracks.iter_mut() {
+                if let TrackSizingFunction::Fraction(fr) = track.sizing {
+                    track.base_size = track.base_size.max(fr * fraction);
+                }
+            }
+        }
+    }
+
+    tracks.into_iter().map(|t| t.base_size).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_cursor_row_wraps_to_next_row() {
+        let mut col = 0usize;
+        let mut row = 0usize;
+        advance_cursor(GridAutoFlow::Row, 2, 2, &mut col, &mut row);
+        assert_eq!((col, row), (1, 0));
+        advance_cursor(GridAutoFlow::Row, 2, 2, &mut col, &mut row);
+        assert_eq!((col, row), (0, 1));
+    }
+
+    #[test]
+    fn advance_cursor_column_wraps_to_next_column() {
+        let mut col = 0usize;
+        let mut row = 0usize;
+        advance_cursor(GridAutoFlow::Column, 2, 2, &mut col, &mut row);
+        assert_eq!((col, row), (0, 1));
+        advance_cursor(GridAutoFlow::Column, 2, 2, &mut col, &mut row);
+        assert_eq!((col, row), (1, 0));
+    }
+
+    #[test]
+    fn clamp_explicit_placement_passes_through_small_values() {
+        assert_eq!(clamp_explicit_placement(1, 1), (0, 1));
+        assert_eq!(clamp_explicit_placement(3, 2), (2, 4));
+    }
+
+    #[test]
+    fn clamp_explicit_placement_caps_runaway_line_numbers() {
+        let (start, end) = clamp_explicit_placement(1_000_000, 1);
+        assert_eq!(start, MAX_EXPLICIT_GRID_LINE);
+        assert_eq!(end, MAX_EXPLICIT_GRID_LINE + 1);
+
+        let (start, end) = clamp_explicit_placement(1, 1_000_000);
+        assert_eq!(start, 0);
+        assert_eq!(end, MAX_EXPLICIT_GRID_LINE + 1);
+    }
+
+    #[test]
+    fn uses_automatic_min_size_only_for_visible_overflow() {
+        assert!(uses_automatic_min_size(Overflow::Visible));
+        assert!(!uses_automatic_min_size(Overflow::Hidden));
+        assert!(!uses_automatic_min_size(Overflow::Scroll));
+    }
+
+    #[test]
+    fn resolve_absolute_offset_falls_back_to_inline_start_edge() {
+        let style = Style::default();
+        let containing_block = Size { width: Number::Defined(100.0), height: Number::Defined(100.0) };
+        let size = Size { width: 20.0, height: 10.0 };
+
+        let (x, _y) = resolve_absolute_offset(&style, Direction::LTR, containing_block, size);
+        assert_eq!(x, 0.0);
+
+        let (x, _y) = resolve_absolute_offset(&style, Direction::RTL, containing_block, size);
+        assert_eq!(x, 80.0);
+    }
+
+    /// Builds a `content_contribution` closure for [`size_tracks`] that
+    /// returns the next value from `contributions` on each call, in order —
+    /// a stand-in for keying off each item's real (distinct) `NodeId`, which
+    /// isn't constructible here without the rest of the `azul_core` tree.
+    fn contributions_in_order(contributions: Vec<f32>) -> impl Fn(NodeId) -> f32 {
+        let next = std::cell::Cell::new(0usize);
+        move |_id| {
+            let i = next.get();
+            next.set(i + 1);
+            contributions[i]
+        }
+    }
+
+    #[test]
+    fn size_tracks_grows_single_span_intrinsic_track_to_content() {
+        let defs = vec![TrackSizingFunction::Auto];
+        let items = vec![GridItem { id: NodeId::ZERO, column: (0, 1), row: (0, 1) }];
+        let spans = vec![(0, 1)];
+        let sizes = size_tracks(&defs, Number::Undefined, 0.0, &items, &spans, contributions_in_order(vec![42.0]));
+        assert_eq!(sizes, vec![42.0]);
+    }
+
+    #[test]
+    fn size_tracks_distributes_spanning_item_across_intrinsic_tracks() {
+        let defs = vec![TrackSizingFunction::Auto, TrackSizingFunction::Auto];
+        let items = vec![GridItem { id: NodeId::ZERO, column: (0, 2), row: (0, 1) }];
+        let spans = vec![(0, 2)];
+        let sizes = size_tracks(&defs, Number::Undefined, 0.0, &items, &spans, contributions_in_order(vec![100.0]));
+        assert_eq!(sizes, vec![50.0, 50.0]);
+    }
+
+    #[test]
+    fn size_tracks_accounts_for_gap_when_distributing_spanning_item() {
+        let defs = vec![TrackSizingFunction::Auto, TrackSizingFunction::Auto];
+        let items = vec![GridItem { id: NodeId::ZERO, column: (0, 2), row: (0, 1) }];
+        let spans = vec![(0, 2)];
+        // 10px of the 100px content contribution is already spent on the gap
+        // between the two tracks, so only 90px should be split across them.
+        let sizes = size_tracks(&defs, Number::Undefined, 10.0, &items, &spans, contributions_in_order(vec![100.0]));
+        assert_eq!(sizes, vec![45.0, 45.0]);
+    }
+
+    #[test]
+    fn size_tracks_expands_fr_tracks_to_fill_available_space() {
+        let defs = vec![TrackSizingFunction::Pixels(20.0), TrackSizingFunction::Fraction(1.0), TrackSizingFunction::Fraction(3.0)];
+        let items: Vec<GridItem> = Vec::new();
+        let spans: Vec<(usize, usize)> = Vec::new();
+        let sizes = size_tracks(&defs, Number::Defined(100.0), 0.0, &items, &spans, contributions_in_order(vec![]));
+        assert_eq!(sizes, vec![20.0, 20.0, 60.0]);
+    }
+
+    #[test]
+    fn eval_calc_adds_and_subtracts_lengths() {
+        let node = CalcNode::Sub(
+            Box::new(CalcNode::Percent(100.0)),
+            Box::new(CalcNode::Px(20.0)),
+        );
+        assert_eq!(eval_calc(&node, Number::Defined(200.0)), Number::Defined(180.0));
+
+        let node = CalcNode::Add(Box::new(CalcNode::Px(10.0)), Box::new(CalcNode::Px(5.0)));
+        assert_eq!(eval_calc(&node, Number::Undefined), Number::Defined(15.0));
+    }
+
+    #[test]
+    fn eval_calc_rejects_multiplying_two_lengths() {
+        let node = CalcNode::Mul(Box::new(CalcNode::Px(50.0)), Box::new(CalcNode::Px(3.0)));
+        assert_eq!(eval_calc(&node, Number::Undefined), Number::Undefined);
+
+        let node = CalcNode::Mul(Box::new(CalcNode::Percent(50.0)), Box::new(CalcNode::Px(3.0)));
+        assert_eq!(eval_calc(&node, Number::Defined(100.0)), Number::Undefined);
+    }
+
+    #[test]
+    fn eval_calc_divides_a_length_by_a_plain_length_but_not_by_a_percent() {
+        let node = CalcNode::Div(Box::new(CalcNode::Px(100.0)), Box::new(CalcNode::Px(4.0)));
+        assert_eq!(eval_calc(&node, Number::Undefined), Number::Defined(25.0));
+
+        let node = CalcNode::Div(Box::new(CalcNode::Px(100.0)), Box::new(CalcNode::Percent(50.0)));
+        assert_eq!(eval_calc(&node, Number::Defined(10.0)), Number::Undefined);
+    }
+
+    #[test]
+    fn resolve_handles_calc_dimension() {
+        let node = CalcNode::Add(Box::new(CalcNode::Percent(50.0)), Box::new(CalcNode::Px(10.0)));
+        let dimension = Dimension::Calc(node);
+        assert_eq!(resolve(dimension, Number::Defined(100.0)), Number::Defined(60.0));
+    }
+}
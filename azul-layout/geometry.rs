@@ -0,0 +1,81 @@
+use crate::number::Number;
+use crate::style::FlexDirection;
+
+/// A generic width/height pair, used for both resolved pixel sizes
+/// (`Size<f32>`, `Size<Number>`) and for style-level dimensions
+/// (`Size<Dimension>`).
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Size<T> {
+    pub width: T,
+    pub height: T,
+}
+
+impl<T: Clone> Size<T> {
+    #[inline]
+    pub fn main(&self, direction: FlexDirection) -> T {
+        if direction.is_row() { self.width.clone() } else { self.height.clone() }
+    }
+
+    #[inline]
+    pub fn cross(&self, direction: FlexDirection) -> T {
+        if direction.is_row() { self.height.clone() } else { self.width.clone() }
+    }
+}
+
+impl Size<Number> {
+    pub const fn undefined() -> Size<Number> {
+        Size { width: Number::Undefined, height: Number::Undefined }
+    }
+}
+
+/// A generic left/right/top/bottom box, used for margins, padding, borders
+/// and absolute-position offsets.
+#[derive(Debug, Copy, Clone, Default, PartialEq, PartialOrd)]
+pub struct Offsets<T> {
+    pub left: T,
+    pub right: T,
+    pub top: T,
+    pub bottom: T,
+}
+
+impl Offsets<f32> {
+    #[inline]
+    pub fn horizontal(&self) -> f32 {
+        self.left + self.right
+    }
+
+    #[inline]
+    pub fn vertical(&self) -> f32 {
+        self.top + self.bottom
+    }
+
+    #[inline]
+    pub fn main_start(&self, direction: FlexDirection) -> f32 {
+        if direction.is_row() { self.left } else { self.top }
+    }
+
+    #[inline]
+    pub fn main_end(&self, direction: FlexDirection) -> f32 {
+        if direction.is_row() { self.right } else { self.bottom }
+    }
+
+    #[inline]
+    pub fn cross_start(&self, direction: FlexDirection) -> f32 {
+        if direction.is_row() { self.top } else { self.left }
+    }
+
+    #[inline]
+    pub fn cross_end(&self, direction: FlexDirection) -> f32 {
+        if direction.is_row() { self.bottom } else { self.right }
+    }
+
+    #[inline]
+    pub fn main(&self, direction: FlexDirection) -> f32 {
+        self.main_start(direction) + self.main_end(direction)
+    }
+
+    #[inline]
+    pub fn cross(&self, direction: FlexDirection) -> f32 {
+        self.cross_start(direction) + self.cross_end(direction)
+    }
+}
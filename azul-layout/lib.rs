@@ -50,6 +50,19 @@ pub trait GetStyle {
     fn get_style(&self) -> Style;
 }
 
+/// A CSS `aspect-ratio` (or an image's intrinsic width/height ratio) is only
+/// meaningful as a positive, finite number; anything else is invalid and
+/// behaves as if the property were never set (CSS's "invalid aspect-ratio
+/// behaves as auto"), rather than being clamped into a degenerate value that
+/// blows sizes up to infinity during layout.
+fn valid_aspect_ratio(ratio: f32) -> Number {
+    if ratio > 0.0 && ratio.is_finite() {
+        Number::Defined(ratio)
+    } else {
+        Number::Undefined
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub struct SolvedUi {
     pub solved_rects: NodeDataContainer<PositionedRectangle>,
@@ -94,12 +107,17 @@ impl SolvedUi {
         let styles = display_rects.transform(|node, node_id| {
 
             let image_aspect_ratio = match rect_contents.get(&node_id) {
-                Some(RectContent::Image(w, h)) => Number::Defined(*w as f32 / *h as f32),
+                Some(RectContent::Image(w, h)) => valid_aspect_ratio(*w as f32 / *h as f32),
                 _ => Number::Undefined,
             };
 
             let mut style = node.get_style();
-            style.aspect_ratio = image_aspect_ratio;
+            // An explicit `aspect-ratio` property always wins; an image's
+            // intrinsic ratio is only used as a fallback when the author
+            // didn't set one.
+            if !style.aspect_ratio.is_defined() {
+                style.aspect_ratio = image_aspect_ratio;
+            }
             style
         });
 
@@ -124,14 +142,56 @@ impl GetStyle for DisplayRectangle {
         use crate::style::*;
         use azul_css::{
             PixelValue, LayoutDisplay, LayoutDirection, LayoutWrap, LayoutPosition,
-            LayoutAlignItems, LayoutAlignContent, LayoutJustifyContent,
+            LayoutAlignItems, LayoutAlignSelf, LayoutAlignContent, LayoutJustifyContent,
             LayoutBoxSizing, Overflow as LayoutOverflow, CssPropertyValue,
+            LayoutGridTrackSizingFunction, LayoutGridAutoFlow, LayoutGridLine,
+            LayoutCalcNode, LayoutFlexBasis, StyleDirection, LayoutAspectRatio,
         };
         use azul_core::ui_solver::DEFAULT_FONT_SIZE;
 
         let rect_layout = &self.layout;
         let rect_style = &self.style;
 
+        #[inline]
+        fn translate_track_list(input: Option<CssPropertyValue<Vec<LayoutGridTrackSizingFunction>>>) -> Vec<TrackSizingFunction> {
+            let tracks = match input {
+                None | Some(CssPropertyValue::Auto) | Some(CssPropertyValue::None)
+                | Some(CssPropertyValue::Initial) | Some(CssPropertyValue::Inherit) => return Vec::new(),
+                Some(CssPropertyValue::Exact(tracks)) => tracks,
+            };
+            tracks.into_iter().map(|track| match track {
+                LayoutGridTrackSizingFunction::Pixels(px) => TrackSizingFunction::Pixels(px),
+                LayoutGridTrackSizingFunction::Percent(pct) => TrackSizingFunction::Percent(pct),
+                LayoutGridTrackSizingFunction::Fraction(fr) => TrackSizingFunction::Fraction(fr),
+                LayoutGridTrackSizingFunction::Auto => TrackSizingFunction::Auto,
+                LayoutGridTrackSizingFunction::MinContent => TrackSizingFunction::MinContent,
+                LayoutGridTrackSizingFunction::MaxContent => TrackSizingFunction::MaxContent,
+            }).collect()
+        }
+
+        #[inline]
+        fn translate_grid_placement(input: Option<CssPropertyValue<LayoutGridLine>>) -> GridPlacement {
+            match input {
+                None | Some(CssPropertyValue::Auto) | Some(CssPropertyValue::None)
+                | Some(CssPropertyValue::Initial) | Some(CssPropertyValue::Inherit) => GridPlacement::default(),
+                Some(CssPropertyValue::Exact(LayoutGridLine { start_line, span })) => {
+                    GridPlacement { start_line, span: span.max(1) }
+                },
+            }
+        }
+
+        #[inline]
+        fn translate_calc_node(node: &LayoutCalcNode) -> CalcNode {
+            match node {
+                LayoutCalcNode::Px(px) => CalcNode::Px(*px),
+                LayoutCalcNode::Percent(pct) => CalcNode::Percent(*pct),
+                LayoutCalcNode::Add(a, b) => CalcNode::Add(Box::new(translate_calc_node(a)), Box::new(translate_calc_node(b))),
+                LayoutCalcNode::Sub(a, b) => CalcNode::Sub(Box::new(translate_calc_node(a)), Box::new(translate_calc_node(b))),
+                LayoutCalcNode::Mul(a, b) => CalcNode::Mul(Box::new(translate_calc_node(a)), Box::new(translate_calc_node(b))),
+                LayoutCalcNode::Div(a, b) => CalcNode::Div(Box::new(translate_calc_node(a)), Box::new(translate_calc_node(b))),
+            }
+        }
+
         #[inline]
         fn translate_dimension(input: Option<CssPropertyValue<PixelValue>>) -> Dimension {
             use azul_css::{SizeMetric, EM_HEIGHT, PT_TO_PX};
@@ -146,10 +206,41 @@ impl GetStyle for DisplayRectangle {
                     SizeMetric::Percent => Dimension::Percent(pixel_value.number.get()),
                     SizeMetric::Pt => Dimension::Pixels(pixel_value.number.get() * PT_TO_PX),
                     SizeMetric::Em => Dimension::Pixels(pixel_value.number.get() * EM_HEIGHT),
+                    SizeMetric::Calc(ref calc_node) => Dimension::Calc(translate_calc_node(calc_node)),
                 }
             }
         }
 
+        #[inline]
+        fn translate_flex_basis(input: Option<CssPropertyValue<LayoutFlexBasis>>) -> Dimension {
+            use azul_css::{SizeMetric, EM_HEIGHT, PT_TO_PX};
+            match input {
+                None | Some(CssPropertyValue::Auto) | Some(CssPropertyValue::None)
+                | Some(CssPropertyValue::Initial) | Some(CssPropertyValue::Inherit) => Dimension::Auto,
+                Some(CssPropertyValue::Exact(LayoutFlexBasis::Auto)) => Dimension::Auto,
+                Some(CssPropertyValue::Exact(LayoutFlexBasis::Content)) => Dimension::Content,
+                Some(CssPropertyValue::Exact(LayoutFlexBasis::Exact(pixel_value))) => match pixel_value.metric {
+                    SizeMetric::Px => Dimension::Pixels(pixel_value.number.get()),
+                    SizeMetric::Percent => Dimension::Percent(pixel_value.number.get()),
+                    SizeMetric::Pt => Dimension::Pixels(pixel_value.number.get() * PT_TO_PX),
+                    SizeMetric::Em => Dimension::Pixels(pixel_value.number.get() * EM_HEIGHT),
+                    SizeMetric::Calc(ref calc_node) => Dimension::Calc(translate_calc_node(calc_node)),
+                },
+            }
+        }
+
+        // Unlike other dimensions, an *unset* min-width/min-height isn't
+        // `Dimension::Undefined` (treated as "no constraint") but CSS's
+        // `auto` keyword, which resolves to the box's content-based minimum
+        // size during layout (see `algo::min_content_size`).
+        #[inline]
+        fn translate_min_dimension(input: Option<CssPropertyValue<PixelValue>>) -> Dimension {
+            match input {
+                None => Dimension::Auto,
+                _ => translate_dimension(input),
+            }
+        }
+
         Style {
             display: match rect_layout.display {
                 None => Display::Flex,
@@ -158,6 +249,7 @@ impl GetStyle for DisplayRectangle {
                 Some(CssPropertyValue::Initial) => Display::Flex,
                 Some(CssPropertyValue::Inherit) => Display::Flex,
                 Some(CssPropertyValue::Exact(LayoutDisplay::Flex)) => Display::Flex,
+                Some(CssPropertyValue::Exact(LayoutDisplay::Grid)) => Display::Grid,
                 Some(CssPropertyValue::Exact(LayoutDisplay::Inline)) => Display::Inline,
             },
             box_sizing: match rect_layout.box_sizing.unwrap_or_default().get_property_or_default() {
@@ -171,7 +263,11 @@ impl GetStyle for DisplayRectangle {
                 Some(LayoutPosition::Absolute) => PositionType::Absolute,
                 None => PositionType::Relative,
             },
-            direction: Direction::LTR,
+            direction: match rect_style.direction.unwrap_or_default().get_property_or_default() {
+                Some(StyleDirection::Ltr) => Direction::LTR,
+                Some(StyleDirection::Rtl) => Direction::RTL,
+                None => Direction::LTR,
+            },
             flex_direction: match rect_layout.direction.unwrap_or_default().get_property_or_default() {
                 Some(LayoutDirection::Row) => FlexDirection::Row,
                 Some(LayoutDirection::RowReverse) => FlexDirection::RowReverse,
@@ -189,7 +285,7 @@ impl GetStyle for DisplayRectangle {
                 Some(LayoutOverflow::Auto) => Overflow::Scroll,
                 Some(LayoutOverflow::Hidden) => Overflow::Hidden,
                 Some(LayoutOverflow::Visible) => Overflow::Visible,
-                None => Overflow::Scroll,
+                None => Overflow::Visible,
             },
             align_items: match rect_layout.align_items.unwrap_or_default().get_property_or_default() {
                 Some(LayoutAlignItems::Stretch) => AlignItems::Stretch,
@@ -247,16 +343,37 @@ impl GetStyle for DisplayRectangle {
                 height: translate_dimension(rect_layout.height.map(|prop| prop.map_property(|l| l.0))),
             },
             min_size: Size {
-                width: translate_dimension(rect_layout.min_width.map(|prop| prop.map_property(|l| l.0))),
-                height: translate_dimension(rect_layout.min_height.map(|prop| prop.map_property(|l| l.0))),
+                width: translate_min_dimension(rect_layout.min_width.map(|prop| prop.map_property(|l| l.0))),
+                height: translate_min_dimension(rect_layout.min_height.map(|prop| prop.map_property(|l| l.0))),
             },
             max_size: Size {
                 width: translate_dimension(rect_layout.max_width.map(|prop| prop.map_property(|l| l.0))),
                 height: translate_dimension(rect_layout.max_height.map(|prop| prop.map_property(|l| l.0))),
             },
-            align_self: AlignSelf::Auto, // todo!
-            flex_basis: Dimension::Auto, // todo!
-            aspect_ratio: Number::Undefined,
+            align_self: match rect_layout.align_self.unwrap_or_default().get_property_or_default() {
+                Some(LayoutAlignSelf::Auto) => AlignSelf::Auto,
+                Some(LayoutAlignSelf::Stretch) => AlignSelf::Stretch,
+                Some(LayoutAlignSelf::Center) => AlignSelf::Center,
+                Some(LayoutAlignSelf::Start) => AlignSelf::FlexStart,
+                Some(LayoutAlignSelf::End) => AlignSelf::FlexEnd,
+                None => AlignSelf::Auto,
+            },
+            flex_basis: translate_flex_basis(rect_layout.flex_basis.clone()),
+            aspect_ratio: match rect_layout.aspect_ratio.unwrap_or_default().get_property_or_default() {
+                None | Some(LayoutAspectRatio::Auto) => Number::Undefined,
+                Some(LayoutAspectRatio::Ratio(ratio)) => valid_aspect_ratio(ratio.get()),
+            },
+            grid_template_columns: translate_track_list(rect_layout.grid_template_columns.clone()),
+            grid_template_rows: translate_track_list(rect_layout.grid_template_rows.clone()),
+            grid_auto_flow: match rect_layout.grid_auto_flow.unwrap_or_default().get_property_or_default() {
+                Some(LayoutGridAutoFlow::Row) => GridAutoFlow::Row,
+                Some(LayoutGridAutoFlow::Column) => GridAutoFlow::Column,
+                None => GridAutoFlow::Row,
+            },
+            grid_row: translate_grid_placement(rect_layout.grid_row.clone()),
+            grid_column: translate_grid_placement(rect_layout.grid_column.clone()),
+            row_gap: translate_dimension(rect_layout.row_gap.map(|prop| prop.map_property(|g| g.0))),
+            column_gap: translate_dimension(rect_layout.column_gap.map(|prop| prop.map_property(|g| g.0))),
             font_size_px: rect_style.font_size.and_then(|fs| fs.get_property_owned()).unwrap_or(DEFAULT_FONT_SIZE).0,
             line_height: rect_style.line_height.and_then(|lh| lh.map_property(|lh| lh.0).get_property_owned()).map(|lh| lh.get()),
             letter_spacing: rect_style.letter_spacing.and_then(|ls| ls.map_property(|ls| ls.0).get_property_owned()),
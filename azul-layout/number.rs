@@ -0,0 +1,93 @@
+use std::ops::{Add, Sub, Mul, Div};
+
+/// A `f32` that can be either defined or undefined, used throughout the layout
+/// algorithm so that "this axis has no resolved size yet" can be threaded
+/// through arithmetic without a sentinel value like `NAN` or `-1.0`.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum Number {
+    Defined(f32),
+    Undefined,
+}
+
+impl Default for Number {
+    fn default() -> Number {
+        Number::Undefined
+    }
+}
+
+impl Number {
+    #[inline]
+    pub fn is_defined(self) -> bool {
+        match self {
+            Number::Defined(_) => true,
+            Number::Undefined => false,
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_or(self, default: f32) -> f32 {
+        match self {
+            Number::Defined(val) => val,
+            Number::Undefined => default,
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_or_else(self, f: impl FnOnce() -> f32) -> f32 {
+        match self {
+            Number::Defined(val) => val,
+            Number::Undefined => f(),
+        }
+    }
+
+    #[inline]
+    pub fn unwrap_or_zero(self) -> f32 {
+        self.unwrap_or(0.0)
+    }
+}
+
+impl From<f32> for Number {
+    fn from(value: f32) -> Self {
+        Number::Defined(value)
+    }
+}
+
+impl Add<f32> for Number {
+    type Output = Number;
+    fn add(self, rhs: f32) -> Number {
+        match self {
+            Number::Defined(val) => Number::Defined(val + rhs),
+            Number::Undefined => Number::Undefined,
+        }
+    }
+}
+
+impl Sub<f32> for Number {
+    type Output = Number;
+    fn sub(self, rhs: f32) -> Number {
+        match self {
+            Number::Defined(val) => Number::Defined(val - rhs),
+            Number::Undefined => Number::Undefined,
+        }
+    }
+}
+
+impl Mul<f32> for Number {
+    type Output = Number;
+    fn mul(self, rhs: f32) -> Number {
+        match self {
+            Number::Defined(val) => Number::Defined(val * rhs),
+            Number::Undefined => Number::Undefined,
+        }
+    }
+}
+
+impl Div<f32> for Number {
+    type Output = Number;
+    fn div(self, rhs: f32) -> Number {
+        match self {
+            Number::Defined(val) => Number::Defined(val / rhs),
+            Number::Undefined => Number::Undefined,
+        }
+    }
+}
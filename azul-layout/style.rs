@@ -0,0 +1,312 @@
+use crate::geometry::{Size, Offsets};
+use crate::number::Number;
+
+/// A parsed dimension, the result of translating a single CSS length-ish
+/// value (`width`, `margin-left`, `flex-basis`, ...) into something the
+/// layout algorithm can resolve without re-parsing CSS.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Dimension {
+    Undefined,
+    Auto,
+    Pixels(f32),
+    Percent(f32),
+    /// A parsed `calc()` expression, e.g. `calc(100% - 20px)`. Kept as a tree
+    /// rather than eagerly collapsed since evaluating it requires the
+    /// percentage basis, which isn't known until layout runs.
+    Calc(CalcNode),
+    /// `flex-basis: content` — unlike `Auto` (which falls back to the main
+    /// `size` property), this always resolves to the item's content size.
+    Content,
+}
+
+impl Default for Dimension {
+    fn default() -> Self {
+        Dimension::Undefined
+    }
+}
+
+/// One node of a parsed `calc()` expression, e.g. `calc(100% - 20px)`
+/// parses to `Sub(Percent(100.0), Px(20.0))`.
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum CalcNode {
+    Px(f32),
+    Percent(f32),
+    Add(Box<CalcNode>, Box<CalcNode>),
+    Sub(Box<CalcNode>, Box<CalcNode>),
+    Mul(Box<CalcNode>, Box<CalcNode>),
+    Div(Box<CalcNode>, Box<CalcNode>),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Display {
+    Flex,
+    Grid,
+    Inline,
+    None,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Display::Flex
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BoxSizing {
+    ContentBox,
+    BorderBox,
+}
+
+impl Default for BoxSizing {
+    fn default() -> Self {
+        BoxSizing::ContentBox
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PositionType {
+    Relative,
+    Absolute,
+}
+
+impl Default for PositionType {
+    fn default() -> Self {
+        PositionType::Relative
+    }
+}
+
+/// The inline/block writing direction of a box, from the CSS `direction`
+/// property. Affects how the flex algorithm resolves `FlexDirection::Row`,
+/// `justify-content` and physical left/right offsets; output rects are
+/// always in physical coordinates regardless of this value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    LTR,
+    RTL,
+}
+
+impl Default for Direction {
+    fn default() -> Self {
+        Direction::LTR
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlexDirection {
+    Row,
+    RowReverse,
+    Column,
+    ColumnReverse,
+}
+
+impl Default for FlexDirection {
+    fn default() -> Self {
+        FlexDirection::Row
+    }
+}
+
+impl FlexDirection {
+    #[inline]
+    pub(crate) fn is_row(self) -> bool {
+        match self {
+            FlexDirection::Row | FlexDirection::RowReverse => true,
+            FlexDirection::Column | FlexDirection::ColumnReverse => false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn is_column(self) -> bool {
+        !self.is_row()
+    }
+
+    #[inline]
+    pub(crate) fn is_reverse(self) -> bool {
+        match self {
+            FlexDirection::RowReverse | FlexDirection::ColumnReverse => true,
+            FlexDirection::Row | FlexDirection::Column => false,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum FlexWrap {
+    NoWrap,
+    Wrap,
+    WrapReverse,
+}
+
+impl Default for FlexWrap {
+    fn default() -> Self {
+        FlexWrap::NoWrap
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Overflow {
+    Visible,
+    Hidden,
+    Scroll,
+}
+
+impl Default for Overflow {
+    fn default() -> Self {
+        Overflow::Visible
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AlignItems {
+    FlexStart,
+    FlexEnd,
+    Center,
+    Baseline,
+    Stretch,
+}
+
+impl Default for AlignItems {
+    fn default() -> Self {
+        AlignItems::Stretch
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AlignSelf {
+    Auto,
+    FlexStart,
+    FlexEnd,
+    Center,
+    Baseline,
+    Stretch,
+}
+
+impl Default for AlignSelf {
+    fn default() -> Self {
+        AlignSelf::Auto
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum AlignContent {
+    FlexStart,
+    FlexEnd,
+    Center,
+    Stretch,
+    SpaceBetween,
+    SpaceAround,
+}
+
+impl Default for AlignContent {
+    fn default() -> Self {
+        AlignContent::Stretch
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JustifyContent {
+    FlexStart,
+    FlexEnd,
+    Center,
+    SpaceBetween,
+    SpaceAround,
+    SpaceEvenly,
+}
+
+impl Default for JustifyContent {
+    fn default() -> Self {
+        JustifyContent::FlexStart
+    }
+}
+
+/// Which axis the implicit grid grows along when auto-placing items that
+/// weren't given an explicit `grid-row` / `grid-column`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GridAutoFlow {
+    Row,
+    Column,
+}
+
+impl Default for GridAutoFlow {
+    fn default() -> Self {
+        GridAutoFlow::Row
+    }
+}
+
+/// A single track (column or row) sizing function, i.e. one entry of a
+/// `grid-template-columns` / `grid-template-rows` track list.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+pub enum TrackSizingFunction {
+    Pixels(f32),
+    Percent(f32),
+    Fraction(f32),
+    Auto,
+    MinContent,
+    MaxContent,
+}
+
+impl TrackSizingFunction {
+    #[inline]
+    pub(crate) fn is_flexible(self) -> bool {
+        matches!(self, TrackSizingFunction::Fraction(_))
+    }
+
+    #[inline]
+    pub(crate) fn is_intrinsic(self) -> bool {
+        matches!(
+            self,
+            TrackSizingFunction::Auto | TrackSizingFunction::MinContent | TrackSizingFunction::MaxContent
+        )
+    }
+}
+
+/// A resolved `grid-row` / `grid-column`: a 1-based start line and a span.
+/// `start_line` of `0` means "not placed explicitly", letting auto-placement pick
+/// a cursor position instead.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct GridPlacement {
+    pub start_line: i32,
+    pub span: u32,
+}
+
+impl GridPlacement {
+    pub(crate) fn is_auto(self) -> bool {
+        self.start_line == 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Style {
+    pub display: Display,
+    pub box_sizing: BoxSizing,
+    pub position_type: PositionType,
+    pub direction: Direction,
+    pub flex_direction: FlexDirection,
+    pub flex_wrap: FlexWrap,
+    pub overflow: Overflow,
+    pub align_items: AlignItems,
+    pub align_self: AlignSelf,
+    pub align_content: AlignContent,
+    pub justify_content: JustifyContent,
+    pub position: Offsets<Dimension>,
+    pub margin: Offsets<Dimension>,
+    pub padding: Offsets<Dimension>,
+    pub border: Offsets<Dimension>,
+    pub flex_grow: f32,
+    pub flex_shrink: f32,
+    pub flex_basis: Dimension,
+    pub size: Size<Dimension>,
+    pub min_size: Size<Dimension>,
+    pub max_size: Size<Dimension>,
+    pub aspect_ratio: Number,
+    pub grid_template_columns: Vec<TrackSizingFunction>,
+    pub grid_template_rows: Vec<TrackSizingFunction>,
+    pub grid_auto_flow: GridAutoFlow,
+    pub grid_row: GridPlacement,
+    pub grid_column: GridPlacement,
+    pub row_gap: Dimension,
+    pub column_gap: Dimension,
+    pub font_size_px: f32,
+    pub line_height: Option<f32>,
+    pub letter_spacing: Option<f32>,
+    pub word_spacing: Option<f32>,
+    pub tab_width: Option<f32>,
+}
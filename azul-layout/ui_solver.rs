@@ -0,0 +1,18 @@
+//! Text measurement helpers over the `GetTextLayout` trait that
+//! [`crate::algo`] uses to measure text content.
+//!
+//! This module only exists when the `text_layout` feature is enabled. Its
+//! helpers are generic over any `GetTextLayout` impl, like `algo` itself.
+
+use azul_core::traits::GetTextLayout;
+
+use crate::geometry::Size;
+use crate::number::Number;
+
+/// Measures the content size of a piece of text at a given available width,
+/// e.g. `Number::Undefined` for its unconstrained (longest line) size, or
+/// `Number::Defined(0.0)` for its min-content (longest unbreakable run) size.
+pub fn text_content_size<T: GetTextLayout>(content: &mut T, available_width: Number) -> Size<f32> {
+    let laid_out = content.get_text_layout(available_width);
+    Size { width: laid_out.width, height: laid_out.height }
+}